@@ -0,0 +1,181 @@
+use actix_web::web::Payload;
+use actix_web::{web, HttpRequest, HttpResponse};
+use log::debug;
+use meilisearch_lib::index_controller::Update;
+use meilisearch_lib::MeiliSearch;
+use milli::update::{IndexDocumentsMethod, UpdateFormat};
+use serde::Deserialize;
+
+use crate::error::ResponseError;
+use crate::extractors::authentication::{policies::*, GuardedData};
+use crate::task::TaskResponse;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::resource("")
+            .route(web::post().to(add_documents))
+            .route(web::put().to(update_documents))
+            .route(web::delete().to(clear_all_documents)),
+    )
+    .service(web::resource("/delete-batch").route(web::post().to(delete_documents)));
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDocumentsQuery {
+    primary_key: Option<String>,
+}
+
+pub async fn add_documents(
+    meilisearch: GuardedData<Private, MeiliSearch>,
+    path: web::Path<String>,
+    params: web::Query<UpdateDocumentsQuery>,
+    body: Payload,
+    req: HttpRequest,
+) -> Result<HttpResponse, ResponseError> {
+    document_addition(
+        IndexDocumentsMethod::ReplaceDocuments,
+        meilisearch,
+        path.into_inner(),
+        params.into_inner().primary_key,
+        body,
+        req,
+    )
+    .await
+}
+
+pub async fn update_documents(
+    meilisearch: GuardedData<Private, MeiliSearch>,
+    path: web::Path<String>,
+    params: web::Query<UpdateDocumentsQuery>,
+    body: Payload,
+    req: HttpRequest,
+) -> Result<HttpResponse, ResponseError> {
+    document_addition(
+        IndexDocumentsMethod::UpdateDocuments,
+        meilisearch,
+        path.into_inner(),
+        params.into_inner().primary_key,
+        body,
+        req,
+    )
+    .await
+}
+
+/// Picks the `UpdateFormat` to parse the body as from its `Content-Type`, defaulting to JSON
+/// when the header is absent or unrecognized. Only the MIME essence (the part before any `;
+/// charset=...` parameter) is compared, so `text/csv; charset=utf-8` is still recognized as CSV.
+fn update_format_from_content_type(req: &HttpRequest) -> UpdateFormat {
+    let mime = req
+        .headers()
+        .get(actix_web::http::header::CONTENT_TYPE)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.split(';').next())
+        .map(str::trim);
+
+    match mime {
+        Some("application/x-ndjson") => UpdateFormat::JsonStream,
+        Some("text/csv") => UpdateFormat::Csv,
+        _ => UpdateFormat::Json,
+    }
+}
+
+async fn document_addition(
+    method: IndexDocumentsMethod,
+    meilisearch: GuardedData<Private, MeiliSearch>,
+    index_uid: String,
+    primary_key: Option<String>,
+    body: Payload,
+    req: HttpRequest,
+) -> Result<HttpResponse, ResponseError> {
+    let format = update_format_from_content_type(&req);
+
+    let update = Update::DocumentsAddition {
+        method,
+        format,
+        primary_key,
+        payload: body,
+    };
+    let task: TaskResponse = meilisearch.register_update(index_uid, update).await?.into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+pub async fn clear_all_documents(
+    meilisearch: GuardedData<Private, MeiliSearch>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let task: TaskResponse = meilisearch
+        .register_update(path.into_inner(), Update::ClearDocuments)
+        .await?
+        .into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+pub async fn delete_documents(
+    meilisearch: GuardedData<Private, MeiliSearch>,
+    path: web::Path<String>,
+    body: web::Json<Vec<serde_json::Value>>,
+) -> Result<HttpResponse, ResponseError> {
+    let ids = body.into_inner();
+    let update = Update::DeleteDocuments(ids);
+    let task: TaskResponse = meilisearch
+        .register_update(path.into_inner(), update)
+        .await?
+        .into();
+
+    debug!("returns: {:?}", task);
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    fn request_with_content_type(content_type: &str) -> HttpRequest {
+        TestRequest::default()
+            .insert_header((actix_web::http::header::CONTENT_TYPE, content_type))
+            .to_http_request()
+    }
+
+    #[test]
+    fn detects_ndjson_ignoring_charset() {
+        let req = request_with_content_type("application/x-ndjson; charset=utf-8");
+        assert!(matches!(
+            update_format_from_content_type(&req),
+            UpdateFormat::JsonStream
+        ));
+    }
+
+    #[test]
+    fn detects_csv_ignoring_charset() {
+        let req = request_with_content_type("text/csv; charset=utf-8");
+        assert!(matches!(
+            update_format_from_content_type(&req),
+            UpdateFormat::Csv
+        ));
+    }
+
+    #[test]
+    fn defaults_to_json_without_content_type() {
+        let req = TestRequest::default().to_http_request();
+        assert!(matches!(
+            update_format_from_content_type(&req),
+            UpdateFormat::Json
+        ));
+    }
+
+    #[test]
+    fn defaults_to_json_for_unrecognized_content_type() {
+        let req = request_with_content_type("application/octet-stream");
+        assert!(matches!(
+            update_format_from_content_type(&req),
+            UpdateFormat::Json
+        ));
+    }
+}
@@ -1,7 +1,7 @@
 use actix_web::{web, HttpRequest, HttpResponse};
 use chrono::{DateTime, Utc};
 use log::debug;
-use meilisearch_lib::index_controller::Update;
+use meilisearch_lib::index_controller::{IndexSettings, IndexSwap, Update};
 use meilisearch_lib::MeiliSearch;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
@@ -22,6 +22,7 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
             .route(web::get().to(list_indexes))
             .route(web::post().to(create_index)),
     )
+    .service(web::resource("/swap").route(web::post().to(swap_indexes)))
     .service(
         web::scope("/{index_uid}")
             .service(
@@ -75,6 +76,17 @@ pub async fn create_index(
     Ok(HttpResponse::Created().json(task))
 }
 
+pub async fn swap_indexes(
+    meilisearch: GuardedData<Private, MeiliSearch>,
+    body: web::Json<Vec<IndexSwap>>,
+) -> Result<HttpResponse, ResponseError> {
+    let swaps = body.into_inner();
+    debug!("called with params: {:?}", swaps);
+    meilisearch.swap_indices(swaps).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct UpdateIndexRequest {
@@ -102,29 +114,28 @@ pub async fn get_index(
 }
 
 pub async fn update_index(
-    _meilisearch: GuardedData<Private, MeiliSearch>,
-    _path: web::Path<String>,
-    _body: web::Json<UpdateIndexRequest>,
-    _req: HttpRequest,
-    _analytics: web::Data<dyn Analytics>,
+    meilisearch: GuardedData<Private, MeiliSearch>,
+    path: web::Path<String>,
+    body: web::Json<UpdateIndexRequest>,
+    req: HttpRequest,
+    analytics: web::Data<dyn Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
-    todo!()
-    // debug!("called with params: {:?}", body);
-    // let body = body.into_inner();
-    // analytics.publish(
-    //     "Index Updated".to_string(),
-    //     json!({ "primary_key": body.primary_key}),
-    //     Some(&req),
-    // );
-    // let settings = IndexSettings {
-    //     uid: body.uid,
-    //     primary_key: body.primary_key,
-    // };
-    // let meta = meilisearch
-    //     .update_index(path.into_inner(), settings)
-    //     .await?;
-    // debug!("returns: {:?}", meta);
-    // Ok(HttpResponse::Ok().json(meta))
+    debug!("called with params: {:?}", body);
+    let body = body.into_inner();
+    analytics.publish(
+        "Index Updated".to_string(),
+        json!({ "primary_key": body.primary_key}),
+        Some(&req),
+    );
+    let settings = IndexSettings {
+        name: body.uid,
+        primary_key: body.primary_key,
+    };
+    let meta = meilisearch
+        .update_index(path.into_inner(), settings)
+        .await?;
+    debug!("returns: {:?}", meta);
+    Ok(HttpResponse::Ok().json(meta))
 }
 
 pub async fn delete_index(
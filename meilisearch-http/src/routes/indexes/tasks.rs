@@ -0,0 +1,109 @@
+use actix_web::{web, HttpResponse};
+use log::debug;
+use meilisearch_lib::index_controller::UpdateStatus;
+use meilisearch_lib::MeiliSearch;
+use serde::Deserialize;
+
+use crate::error::ResponseError;
+use crate::extractors::authentication::{policies::*, GuardedData};
+use crate::task::TaskResponse;
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::resource("").route(web::get().to(get_all_tasks_status)))
+        .service(web::resource("/{task_id}").route(web::get().to(get_task_status)));
+}
+
+fn default_limit() -> usize {
+    20
+}
+
+/// The states a task can be queried by via the `status` query parameter. Deserializing an
+/// unrecognized value fails (and surfaces as a 400) rather than silently matching every task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum TaskStatus {
+    Enqueued,
+    Processing,
+    Processed,
+    Failed,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct TasksFilterQuery {
+    #[serde(default = "default_limit")]
+    limit: usize,
+    from: Option<u64>,
+    status: Option<TaskStatus>,
+}
+
+fn matches_status(task: &UpdateStatus, status: TaskStatus) -> bool {
+    match status {
+        TaskStatus::Enqueued => matches!(task, UpdateStatus::Enqueued(_)),
+        TaskStatus::Processing => matches!(task, UpdateStatus::Processing(_)),
+        TaskStatus::Processed => matches!(task, UpdateStatus::Processed(_)),
+        TaskStatus::Failed => matches!(task, UpdateStatus::Failed(_)),
+    }
+}
+
+pub async fn get_all_tasks_status(
+    meilisearch: GuardedData<Private, MeiliSearch>,
+    path: web::Path<String>,
+    params: web::Query<TasksFilterQuery>,
+) -> Result<HttpResponse, ResponseError> {
+    let TasksFilterQuery { limit, from, status } = params.into_inner();
+
+    // Tasks are listed newest first, so `from` is the id to page backwards from.
+    let mut all_tasks = meilisearch.all_update_status(path.into_inner()).await?;
+    all_tasks.sort_unstable_by_key(|task| std::cmp::Reverse(task.id()));
+
+    let tasks: Vec<TaskResponse> = all_tasks
+        .into_iter()
+        .filter(|task| status.map_or(true, |status| matches_status(task, status)))
+        .filter(|task| from.map_or(true, |from| task.id() <= from))
+        .take(limit)
+        .map(TaskResponse::from)
+        .collect();
+
+    debug!("returns: {:?}", tasks);
+    Ok(HttpResponse::Ok().json(tasks))
+}
+
+pub async fn get_task_status(
+    meilisearch: GuardedData<Private, MeiliSearch>,
+    path: web::Path<(String, u64)>,
+) -> Result<HttpResponse, ResponseError> {
+    let (index_uid, task_id) = path.into_inner();
+
+    match meilisearch.update_status(index_uid, task_id).await? {
+        Some(task) => {
+            let task = TaskResponse::from(task);
+            debug!("returns: {:?}", task);
+            Ok(HttpResponse::Ok().json(task))
+        }
+        None => Ok(HttpResponse::NotFound().finish()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_status_value() {
+        assert!(serde_urlencoded::from_str::<TasksFilterQuery>("status=done").is_err());
+    }
+
+    #[test]
+    fn accepts_known_status_values() {
+        let query: TasksFilterQuery = serde_urlencoded::from_str("status=processing").unwrap();
+        assert_eq!(query.status, Some(TaskStatus::Processing));
+    }
+
+    #[test]
+    fn limit_defaults_when_absent() {
+        let query: TasksFilterQuery = serde_urlencoded::from_str("").unwrap();
+        assert_eq!(query.limit, default_limit());
+        assert_eq!(query.status, None);
+    }
+}
@@ -51,11 +51,37 @@ pub struct IndexSettings {
     pub primary_key: Option<String>,
 }
 
+/// A pair of index uids whose underlying uuids should be swapped with one another.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexSwap {
+    pub indexes: (String, String),
+}
+
+
+/// Default size of the channel used to stream document payloads to the update actor. Large
+/// imports that outpace this buffer apply backpressure to the caller instead of being buffered
+/// in memory.
+const DEFAULT_PAYLOAD_BUFFER_SIZE: usize = 100;
+
+/// Checks that a document id is a type milli accepts as a primary key value: a non-empty string
+/// or a non-negative integer. Anything else (booleans, floats, objects, arrays, null) is rejected
+/// up front instead of being forwarded to the update actor.
+fn validate_document_id(id: &serde_json::Value) -> anyhow::Result<()> {
+    match id {
+        serde_json::Value::String(s) if !s.is_empty() => Ok(()),
+        serde_json::Value::Number(n) if n.is_u64() => Ok(()),
+        other => Err(anyhow::anyhow!(
+            "Document identifier `{}` is invalid: it must be a non-empty string or a non-negative integer",
+            other
+        )),
+    }
+}
 
 pub struct IndexController {
     uuid_resolver: uuid_resolver::UuidResolverHandle,
     index_handle: index_actor::IndexActorHandle,
     update_handle: update_actor::UpdateActorHandle<Bytes>,
+    payload_buffer_size: usize,
 }
 
 enum IndexControllerMsg {
@@ -72,7 +98,18 @@ impl IndexController {
         let uuid_resolver = uuid_resolver::UuidResolverHandle::new();
         let index_actor = index_actor::IndexActorHandle::new(&path);
         let update_handle = update_actor::UpdateActorHandle::new(index_actor.clone(), &path);
-        Self { uuid_resolver, index_handle: index_actor, update_handle }
+        Self {
+            uuid_resolver,
+            index_handle: index_actor,
+            update_handle,
+            payload_buffer_size: DEFAULT_PAYLOAD_BUFFER_SIZE,
+        }
+    }
+
+    /// Overrides the size of the channel used to stream document payloads to the update actor.
+    pub fn with_payload_buffer_size(mut self, payload_buffer_size: usize) -> Self {
+        self.payload_buffer_size = payload_buffer_size;
+        self
     }
 
     pub async fn add_documents(
@@ -85,18 +122,28 @@ impl IndexController {
     ) -> anyhow::Result<UpdateStatus> {
         let uuid = self.uuid_resolver.get_or_create(index).await?;
         let meta = UpdateMeta::DocumentsAddition { method, format, primary_key };
-        let (sender, receiver) = mpsc::channel(10);
+        let (sender, receiver) = mpsc::channel(self.payload_buffer_size);
 
-        // It is necessary to spawn a local task to senf the payload to the update handle to
+        // It is necessary to spawn a local task to send the payload to the update handle to
         // prevent dead_locking between the update_handle::update that waits for the update to be
-        // registered and the update_actor that waits for the the payload to be sent to it.
+        // registered and the update_actor that waits for the payload to be sent to it. Awaiting
+        // on `send` applies backpressure: once the buffer is full, this task stalls until the
+        // update actor has consumed earlier chunks, instead of buffering the whole body in
+        // memory.
         tokio::task::spawn_local(async move {
             while let Some(bytes) = payload.next().await {
-                match bytes {
-                    Ok(bytes) => { sender.send(Ok(bytes)).await; },
+                let result = match bytes {
+                    Ok(bytes) => sender.send(Ok(bytes)).await,
                     Err(e) => {
                         let error: Box<dyn std::error::Error + Sync + Send + 'static> = Box::new(e);
-                        sender.send(Err(error)).await; },
+                        sender.send(Err(error)).await
+                    }
+                };
+
+                // The update actor dropped the receiver, there is no point in reading the rest
+                // of the payload.
+                if result.is_err() {
+                    break;
                 }
             }
         });
@@ -106,16 +153,56 @@ impl IndexController {
         Ok(status)
     }
 
-    fn clear_documents(&self, index: String) -> anyhow::Result<UpdateStatus> {
-        todo!()
+    pub async fn clear_documents(&self, index: String) -> anyhow::Result<UpdateStatus> {
+        let uuid = self.uuid_resolver.get_or_create(index).await?;
+        let meta = UpdateMeta::ClearDocuments;
+        // Clearing carries no document payload. The sender is dropped immediately so the
+        // update actor sees the channel close instead of hanging while draining it.
+        let (sender, receiver) = mpsc::channel(1);
+        drop(sender);
+        let status = self.update_handle.update(meta, receiver, uuid).await?;
+        Ok(status)
     }
 
-    fn delete_documents(&self, index: String, document_ids: Vec<String>) -> anyhow::Result<UpdateStatus> {
-        todo!()
+    pub async fn delete_documents(
+        &self,
+        index: String,
+        document_ids: Vec<serde_json::Value>,
+    ) -> anyhow::Result<UpdateStatus> {
+        for id in &document_ids {
+            validate_document_id(id)?;
+        }
+
+        let uuid = self.uuid_resolver.get_or_create(index).await?;
+        let meta = UpdateMeta::DeleteDocuments;
+        let (sender, receiver) = mpsc::channel(1);
+        // The ids to delete are the only payload this update carries, so they are forwarded
+        // through the same document-bytes channel `add_documents` uses.
+        let ids = serde_json::to_vec(&document_ids)?;
+        sender
+            .send(Ok(Bytes::from(ids)))
+            .await
+            .map_err(|_| anyhow::anyhow!("update actor channel closed"))?;
+        // The sender must be dropped before awaiting `update`, or the update actor would hang
+        // waiting for the channel to close once it has read the ids.
+        drop(sender);
+        let status = self.update_handle.update(meta, receiver, uuid).await?;
+        Ok(status)
     }
 
-    fn update_settings(&self, index_uid: String, settings: Settings) -> anyhow::Result<UpdateStatus> {
-        todo!()
+    pub async fn update_settings(
+        &self,
+        index_uid: String,
+        settings: Settings,
+    ) -> anyhow::Result<UpdateStatus> {
+        let uuid = self.uuid_resolver.get_or_create(index_uid).await?;
+        let meta = UpdateMeta::Settings(settings);
+        // Settings updates carry no document payload. The sender is dropped immediately so the
+        // update actor sees the channel close instead of hanging while draining it.
+        let (sender, receiver) = mpsc::channel(1);
+        drop(sender);
+        let status = self.update_handle.update(meta, receiver, uuid).await?;
+        Ok(status)
     }
 
     pub async fn create_index(&self, index_settings: IndexSettings) -> anyhow::Result<IndexMetadata> {
@@ -129,28 +216,77 @@ impl IndexController {
         todo!()
     }
 
-    fn swap_indices(&self, index1_uid: String, index2_uid: String) -> anyhow::Result<()> {
-        todo!()
+    /// Atomically swaps the uuids backing each pair of index uids in `swaps`.
+    ///
+    /// The remapping happens as a single transaction inside the `uuid_resolver`: either every
+    /// pair is swapped, or none of them are. This lets a fresh index be built under a temporary
+    /// uid and hot-swapped into the uid actually served to users, without ever leaving the
+    /// resolver in a state where an in-flight search could observe only half of the swap.
+    pub async fn swap_indices(&self, swaps: Vec<IndexSwap>) -> anyhow::Result<()> {
+        let swaps = swaps.into_iter().map(|swap| swap.indexes).collect();
+        self.uuid_resolver.swap(swaps).await?;
+        Ok(())
     }
 
     pub fn index(&self, name: String) -> anyhow::Result<Option<std::sync::Arc<milli::Index>>> {
         todo!()
     }
 
-    fn update_status(&self, index: String, id: u64) -> anyhow::Result<Option<UpdateStatus>> {
-        todo!()
+    pub async fn update_status(&self, index: String, id: u64) -> anyhow::Result<Option<UpdateStatus>> {
+        let uuid = match self.uuid_resolver.resolve(index).await? {
+            Some(uuid) => uuid,
+            None => return Ok(None),
+        };
+        let status = self.update_handle.update_status(uuid, id).await?;
+        Ok(status)
     }
 
-    fn all_update_status(&self, index: String) -> anyhow::Result<Vec<UpdateStatus>> {
-        todo!()
+    pub async fn all_update_status(&self, index: String) -> anyhow::Result<Vec<UpdateStatus>> {
+        let uuid = self
+            .uuid_resolver
+            .resolve(index.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Index `{}` not found", index))?;
+        let status = self.update_handle.all_update_status(uuid).await?;
+        Ok(status)
     }
 
     pub fn list_indexes(&self) -> anyhow::Result<Vec<IndexMetadata>> {
         todo!()
     }
 
-    fn update_index(&self, name: String, index_settings: IndexSettings) -> anyhow::Result<IndexMetadata> {
-        todo!()
+    /// Renames an index and/or changes its primary key.
+    ///
+    /// Renaming only touches the `uuid_resolver` mapping: the underlying milli index keeps its
+    /// uuid and all of its data. Changing the primary key is delegated to the index itself, which
+    /// fails if documents with a different inferred key already exist.
+    pub async fn update_index(
+        &self,
+        name: String,
+        index_settings: IndexSettings,
+    ) -> anyhow::Result<IndexMetadata> {
+        let IndexSettings {
+            name: new_uid,
+            primary_key,
+        } = index_settings;
+        let uuid = self
+            .uuid_resolver
+            .resolve(name.clone())
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Index `{}` not found", name))?;
+
+        if let Some(primary_key) = primary_key {
+            self.index_handle
+                .update_primary_key(uuid, primary_key)
+                .await?;
+        }
+
+        if let Some(new_uid) = new_uid {
+            self.uuid_resolver.rename(name, new_uid).await?;
+        }
+
+        let meta = self.index_handle.index_meta(uuid).await?;
+        Ok(meta)
     }
 
     pub async fn search(&self, name: String, query: SearchQuery) -> anyhow::Result<SearchResult> {
@@ -159,3 +295,24 @@ impl IndexController {
         Ok(result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_document_id_accepts_strings_and_non_negative_integers() {
+        assert!(validate_document_id(&serde_json::json!("doc-1")).is_ok());
+        assert!(validate_document_id(&serde_json::json!(42)).is_ok());
+    }
+
+    #[test]
+    fn validate_document_id_rejects_wrong_types() {
+        assert!(validate_document_id(&serde_json::json!("")).is_err());
+        assert!(validate_document_id(&serde_json::json!(-1)).is_err());
+        assert!(validate_document_id(&serde_json::json!(1.5)).is_err());
+        assert!(validate_document_id(&serde_json::json!(true)).is_err());
+        assert!(validate_document_id(&serde_json::json!(null)).is_err());
+        assert!(validate_document_id(&serde_json::json!({"id": 1})).is_err());
+    }
+}